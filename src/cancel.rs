@@ -0,0 +1,56 @@
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::ready;
+
+use crate::{Cancellation, Drive, Ring};
+
+/// A [`Future`] that resolves once the kernel has confirmed an in-flight operation was
+/// cancelled.
+///
+/// Returned by [`Submission::cancel`](crate::Submission::cancel). Unlike the best-effort
+/// cancellation issued from `Drop`, this future only completes once the `CQE` for the
+/// `IORING_OP_ASYNC_CANCEL` request and the original operation's terminating `-ECANCELED`
+/// `CQE` have both been observed, at which point it is safe to say the kernel is no
+/// longer touching any resources the event owned.
+pub struct Cancel<D: Drive> {
+    ring: Ring<D>,
+    cancellation: Option<Cancellation>,
+}
+
+impl<D: Drive> Cancel<D> {
+    pub(crate) fn new(ring: Ring<D>, cancellation: Cancellation) -> Cancel<D> {
+        Cancel { ring, cancellation: Some(cancellation) }
+    }
+
+    fn split(self: Pin<&mut Self>) -> (Pin<&mut Ring<D>>, &mut Option<Cancellation>) {
+        unsafe {
+            let this = Pin::get_unchecked_mut(self);
+            (Pin::new_unchecked(&mut this.ring), &mut this.cancellation)
+        }
+    }
+}
+
+impl<D: Drive> Future for Cancel<D> {
+    type Output = io::Result<()>;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        let (ring, cancellation) = self.split();
+
+        let result = match cancellation {
+            Some(pending) => ready!(ring.poll_cancel(ctx, pending)),
+            None => panic!("polled Cancel after completion"),
+        };
+
+        // Only now, once the cancel and the original op's terminating CQEs have both
+        // been observed, do we drop the `Cancellation` and release the buffers/fds it
+        // guards. Clearing the slot any earlier would both free the resources before
+        // the kernel is done with them and make the next `poll` panic on a pending
+        // future, since `ready!` guarantees there will be a next `poll`.
+        *cancellation = None;
+
+        Poll::Ready(result)
+    }
+}
@@ -0,0 +1,91 @@
+use std::io;
+use std::mem::ManuallyDrop;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::{ready, Stream};
+
+use crate::{Drive, Event, Ring};
+
+/// An [`Event`] that may produce more than one completion for a single submitted SQE.
+///
+/// Multishot operations (multishot accept, `IORING_RECV_MULTISHOT`, multishot poll) keep
+/// posting completions for as long as the kernel marks them with `IORING_CQE_F_MORE`; the
+/// final completion is the one posted without that flag.
+pub trait MultishotEvent: Event {}
+
+/// A [`Stream`] of completions for a [`MultishotEvent`] submitted to io-uring.
+///
+/// Completions can arrive from the kernel faster than this stream is polled; buffering
+/// them between polls is `Ring::poll_multishot`'s job, the same way a plain `Ring::poll`
+/// already holds a single pending completion until it's polled for. `poll_next` only
+/// ever needs to surface one completion per call, so no separate queue is kept here.
+pub struct MultishotSubmission<E: Event, D: Drive> {
+    ring: Ring<D>,
+    event: Option<ManuallyDrop<E>>,
+}
+
+impl<E: MultishotEvent, D: Drive> MultishotSubmission<E, D> {
+    /// Construct a new multishot submission from an event and a driver.
+    pub fn new(event: E, driver: D) -> MultishotSubmission<E, D> {
+        MultishotSubmission {
+            ring: Ring::new(driver),
+            event: Some(ManuallyDrop::new(event)),
+        }
+    }
+
+    /// Access the driver this submission is using
+    pub fn driver(&self) -> &D {
+        self.ring.driver()
+    }
+
+    fn split(self: Pin<&mut Self>) -> (Pin<&mut Ring<D>>, &mut Option<ManuallyDrop<E>>) {
+        unsafe {
+            let this = Pin::get_unchecked_mut(self);
+            (Pin::new_unchecked(&mut this.ring), &mut this.event)
+        }
+    }
+}
+
+impl<E, D> Stream for MultishotSubmission<E, D> where
+    E: MultishotEvent,
+    D: Drive,
+{
+    type Item = io::Result<u32>;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let (ring, event_slot) = self.split();
+
+        let result = match event_slot {
+            Some(event) => {
+                let count = event.sqes_needed();
+                let (result, more) = ready!(ring.poll_multishot(ctx, count, |sqs| unsafe { event.prepare(sqs) }));
+
+                if !more {
+                    // This was the terminating CQE: the kernel will not post any further
+                    // completions for this SQE, so the slot is retired just like a regular
+                    // `Submission` once its single completion arrives.
+                    let event = event_slot.take().unwrap();
+                    drop(ManuallyDrop::into_inner(event));
+                }
+
+                result
+            }
+            None => return Poll::Ready(None),
+        };
+
+        Poll::Ready(Some(result))
+    }
+}
+
+impl<E: MultishotEvent, D: Drive> Drop for MultishotSubmission<E, D> {
+    fn drop(&mut self) {
+        if let Some(event) = &mut self.event {
+            // Ask the kernel to stop posting completions for this SQE. The cancellation's
+            // buffers are only released once `Ring::cancel` observes the terminating CQE,
+            // so outstanding completions can't race with the event's teardown.
+            let cancellation = unsafe { Event::cancel(event) };
+            self.ring.cancel(cancellation);
+        }
+    }
+}
@@ -3,10 +3,15 @@ use std::io;
 use std::mem::ManuallyDrop;
 use std::pin::Pin;
 use std::task::{Context, Poll};
+use std::time::Duration;
 
+use futures_core::future::FusedFuture;
 use futures_core::ready;
 
 use crate::{Event, Drive, Ring};
+use crate::cancel::Cancel;
+use crate::timeout::Timeout;
+use crate::unsubmitted::Unsubmitted;
 
 /// A [`Future`] representing an event submitted to io-uring
 pub struct Submission<E: Event, D: Drive> {
@@ -28,6 +33,60 @@ impl<E: Event, D: Drive> Submission<E, D> {
         self.ring.driver()
     }
 
+    /// Prepare an event's SQEs without flushing them to the kernel.
+    ///
+    /// The returned [`Unsubmitted`] can be combined with others and flushed together in
+    /// a single `io_uring_enter`, rather than paying for a syscall per event.
+    pub fn prepared(event: E, driver: D) -> Unsubmitted<E, D> {
+        Unsubmitted::prepare(event, driver)
+    }
+
+    pub(crate) fn from_prepared(ring: Ring<D>, event: ManuallyDrop<E>) -> Submission<E, D> {
+        Submission { ring, event: Some(event) }
+    }
+
+    /// Attach a deadline to this submission: if the event hasn't completed within `dur`,
+    /// it is cancelled by the kernel via a linked `IORING_OP_LINK_TIMEOUT` SQE.
+    pub fn with_timeout(mut self, dur: Duration) -> Submission<Timeout<E>, D> {
+        let event = ManuallyDrop::into_inner(self.event.take()
+            .expect("called with_timeout on a completed Submission"));
+
+        // Move the ring out without running Submission's Drop impl: the event slot is
+        // already empty, so there is nothing left for that Drop to cancel.
+        let ring = unsafe { std::ptr::read(&self.ring) };
+        std::mem::forget(self);
+
+        Submission {
+            ring,
+            event: Some(ManuallyDrop::new(Timeout::wrap(event, dur))),
+        }
+    }
+
+    /// Actively cancel this submission's in-flight operation.
+    ///
+    /// Unlike dropping the `Submission`, which issues a best-effort cancellation and
+    /// moves on, the returned future only resolves once the kernel has confirmed the
+    /// cancellation, at which point buffers or file descriptors owned by the event are
+    /// provably no longer referenced by the kernel.
+    pub fn cancel(self: Pin<&mut Self>) -> Cancel<D> where D: Clone {
+        let (ring, event_slot) = self.split();
+
+        let cancellation = match event_slot {
+            Some(event) => unsafe { Event::cancel(event) },
+            None => panic!("called cancel on a completed Submission"),
+        };
+        *event_slot = None;
+
+        // `ring` is the one tracking the in-flight operation's kernel registration and
+        // must be the one `Cancel` polls. Swap it out for a fresh, never-submitted ring
+        // so this `Submission` is left with something harmless to drop: the event slot
+        // is already `None`, so nothing will use this `Submission`'s ring again.
+        let driver = ring.driver().clone();
+        let ring = unsafe { std::mem::replace(Pin::get_unchecked_mut(ring), Ring::new(driver)) };
+
+        Cancel::new(ring, cancellation)
+    }
+
     pub fn replace_event(self: Pin<&mut Self>, event: E) {
         let (ring, event_slot) = self.split();
         if let Some(event) = &mut *event_slot {
@@ -65,6 +124,14 @@ impl<E, D> Future for Submission<E, D> where
     }
 }
 
+impl<E, D> FusedFuture for Submission<E, D> where
+    E: Event,
+    D: Drive,
+{
+    fn is_terminated(&self) -> bool {
+        self.event.is_none()
+    }
+}
 
 impl<E: Event, D: Drive> Drop for Submission<E, D> {
     fn drop(&mut self) {
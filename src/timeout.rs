@@ -0,0 +1,93 @@
+use std::time::Duration;
+
+use crate::{Cancellation, Event};
+
+/// An [`Event`] wrapped with a deadline, enforced by a linked `IORING_OP_LINK_TIMEOUT` SQE.
+///
+/// If the wrapped event hasn't completed by the time the deadline elapses, the kernel
+/// cancels it on the wrapper's behalf; the wrapped event then completes with
+/// `-ECANCELED` and the timeout SQE itself completes with `-ETIME`.
+pub struct Timeout<E> {
+    event: E,
+    ts: Option<Box<uring_sys::__kernel_timespec>>,
+}
+
+impl<E: Event> Timeout<E> {
+    pub(crate) fn wrap(event: E, dur: Duration) -> Timeout<E> {
+        Timeout {
+            event,
+            // Boxed so the timespec has a stable address for the duration of the
+            // operation; the kernel reads it asynchronously after `prepare` returns.
+            // Kept behind `Option` so `cancel` can hand ownership of it to the returned
+            // `Cancellation` instead of leaking it on an early drop.
+            ts: Some(Box::new(uring_sys::__kernel_timespec {
+                tv_sec: dur.as_secs() as i64,
+                tv_nsec: dur.subsec_nanos() as i64,
+            })),
+        }
+    }
+}
+
+unsafe impl<E: Event> Event for Timeout<E> {
+    fn sqes_needed(&self) -> u32 {
+        self.event.sqes_needed() + 1
+    }
+
+    unsafe fn prepare(&mut self, sqs: &mut iou::SQEs<'_>) {
+        let (event_sqes, mut rest) = sqs.split(self.event.sqes_needed());
+        self.event.prepare(event_sqes);
+        event_sqes.set_link();
+
+        let mut timeout = rest.single();
+        let ts = self.ts.as_deref().expect("Timeout prepared after it was cancelled");
+        timeout.prep_link_timeout(ts);
+    }
+
+    unsafe fn cancel(&mut self) -> Cancellation {
+        // The boxed timespec must stay alive until the kernel's terminating CQEs for
+        // both the timeout SQE and the event it guards have landed, exactly like any
+        // other resource the wrapped event owns; fold it into the returned
+        // `Cancellation` so an early drop doesn't leak it.
+        let cancellation = self.event.cancel();
+        match self.ts.take() {
+            Some(ts) => cancellation.append(Cancellation::from(ts)),
+            None => cancellation,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Noop;
+
+    unsafe impl Event for Noop {
+        fn sqes_needed(&self) -> u32 { 1 }
+        unsafe fn prepare(&mut self, _sqs: &mut iou::SQEs<'_>) { }
+        unsafe fn cancel(&mut self) -> Cancellation { Cancellation::null() }
+    }
+
+    #[test]
+    fn wrap_converts_duration_to_kernel_timespec() {
+        let timeout = Timeout::wrap(Noop, Duration::new(5, 250));
+        let ts = timeout.ts.as_deref().unwrap();
+        assert_eq!(ts.tv_sec, 5);
+        assert_eq!(ts.tv_nsec, 250);
+    }
+
+    #[test]
+    fn sqes_needed_accounts_for_the_timeout_sqe() {
+        let timeout = Timeout::wrap(Noop, Duration::from_secs(1));
+        assert_eq!(timeout.sqes_needed(), Noop.sqes_needed() + 1);
+    }
+
+    #[test]
+    fn cancel_takes_the_boxed_timespec_instead_of_leaking_it() {
+        // Regression test for leaking `ts` on an early drop: cancelling must hand the
+        // box off to the returned `Cancellation` rather than leave it behind.
+        let mut timeout = Timeout::wrap(Noop, Duration::from_secs(1));
+        unsafe { Event::cancel(&mut timeout) };
+        assert!(timeout.ts.is_none());
+    }
+}
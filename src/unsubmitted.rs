@@ -0,0 +1,59 @@
+use std::mem::ManuallyDrop;
+
+use crate::{Drive, Event, Ring, Submission};
+
+/// An event whose SQEs have been written into the driver's submission queue but not
+/// necessarily flushed to the kernel with `io_uring_enter` yet.
+///
+/// Building this separately from [`Submission`] lets a caller write several
+/// independent operations' SQEs before any of them triggers the `io_uring_enter` that
+/// flushes the queue: [`submit`](Unsubmitted::submit) only converts this into a
+/// pollable [`Submission`], it does not itself flush anything. The syscall happens the
+/// first time any one of those `Submission`s is polled, and since they all write into
+/// the same driver's queue, that single flush carries every SQE prepared on it so far
+/// — amortizing syscall overhead for bursty workloads without needing a separate
+/// explicit "flush everything now" call.
+pub struct Unsubmitted<E: Event, D: Drive> {
+    ring: Ring<D>,
+    event: ManuallyDrop<E>,
+}
+
+impl<E: Event, D: Drive> Unsubmitted<E, D> {
+    pub(crate) fn prepare(event: E, driver: D) -> Unsubmitted<E, D> {
+        let mut ring = Ring::new(driver);
+        let mut event = ManuallyDrop::new(event);
+        let count = event.sqes_needed();
+        ring.prepare(count, |sqs| unsafe { event.prepare(sqs) });
+        Unsubmitted { ring, event }
+    }
+
+    /// Access the driver this event is using
+    pub fn driver(&self) -> &D {
+        self.ring.driver()
+    }
+
+    /// Convert this into a pollable [`Submission`]. The SQEs already written by
+    /// [`prepared`](Submission::prepared) are only flushed to the kernel the first time
+    /// the returned `Submission` is polled.
+    pub fn submit(self) -> Submission<E, D> {
+        // Move the ring and event out without running Unsubmitted's Drop impl, which
+        // would otherwise cancel the event that the new Submission now owns.
+        let ring = unsafe { std::ptr::read(&self.ring) };
+        let event = unsafe { std::ptr::read(&self.event) };
+        std::mem::forget(self);
+        Submission::from_prepared(ring, event)
+    }
+}
+
+impl<E: Event, D: Drive> Drop for Unsubmitted<E, D> {
+    fn drop(&mut self) {
+        // `prepare` already wrote this event's SQE(s) into the driver's submission
+        // queue. Those SQEs may already have reached the kernel by the time this drops
+        // (e.g. another prepared event on the same driver triggered a flush), so,
+        // exactly like every other wrapper in this series, route through
+        // `Event::cancel` and `Ring::cancel` rather than assuming the kernel never saw
+        // them and freeing the event's buffers/fds out from under it.
+        let cancellation = unsafe { Event::cancel(&mut self.event) };
+        self.ring.cancel(cancellation);
+    }
+}